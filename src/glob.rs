@@ -0,0 +1,194 @@
+//! Minimal shell-style glob matcher for `--filter`, `--ignore-file`, and
+//! `--ignore-dir` patterns.
+//!
+//! Supports `*` (any run of non-`/` characters), `?` (exactly one non-`/`
+//! character), `[abc]` / `[a-z]` character classes with `[!...]` negation,
+//! and a literal `**` segment that matches across `/` boundaries. There is
+//! no external crate dependency; patterns are tokenized once and matched
+//! with a small backtracking matcher, which is plenty fast for the short,
+//! hand-written patterns this tool deals with.
+
+use std::path::Path;
+
+#[derive(Debug, Clone)]
+enum Token {
+    Char(char),
+    Any,
+    Star,
+    DoubleStar,
+    /// A `**/` segment: matches zero or more whole path segments, including
+    /// the separator after each one. Kept distinct from `DoubleStar` so that
+    /// `a/**/b` can match `a/b` and `**/foo` can match a top-level `foo`.
+    DoubleStarSlash,
+    Class(Vec<(char, char)>, bool),
+}
+
+fn tokenize(pattern: &str) -> Vec<Token> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '*' => {
+                if chars.get(i + 1) == Some(&'*') {
+                    if chars.get(i + 2) == Some(&'/') {
+                        tokens.push(Token::DoubleStarSlash);
+                        i += 3;
+                    } else {
+                        tokens.push(Token::DoubleStar);
+                        i += 2;
+                    }
+                } else {
+                    tokens.push(Token::Star);
+                    i += 1;
+                }
+            }
+            '?' => {
+                tokens.push(Token::Any);
+                i += 1;
+            }
+            '[' => {
+                let mut j = i + 1;
+                let negate = matches!(chars.get(j), Some('!') | Some('^'));
+                if negate {
+                    j += 1;
+                }
+                let start = j;
+                while j < chars.len() && chars[j] != ']' {
+                    j += 1;
+                }
+                if j >= chars.len() {
+                    // Unterminated class: treat the '[' as a literal character.
+                    tokens.push(Token::Char('['));
+                    i += 1;
+                    continue;
+                }
+                let body = &chars[start..j];
+                let mut ranges = Vec::new();
+                let mut k = 0;
+                while k < body.len() {
+                    if k + 2 < body.len() && body[k + 1] == '-' {
+                        ranges.push((body[k], body[k + 2]));
+                        k += 3;
+                    } else {
+                        ranges.push((body[k], body[k]));
+                        k += 1;
+                    }
+                }
+                tokens.push(Token::Class(ranges, negate));
+                i = j + 1;
+            }
+            c => {
+                tokens.push(Token::Char(c));
+                i += 1;
+            }
+        }
+    }
+    tokens
+}
+
+fn match_rec(tokens: &[Token], text: &[char]) -> bool {
+    let Some(token) = tokens.first() else {
+        return text.is_empty();
+    };
+    let rest = &tokens[1..];
+    match token {
+        Token::Char(c) => text.first() == Some(c) && match_rec(rest, &text[1..]),
+        Token::Any => matches!(text.first(), Some(&c) if c != '/') && match_rec(rest, &text[1..]),
+        Token::Class(ranges, negate) => match text.first() {
+            Some(&c) if c != '/' => {
+                let in_class = ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi);
+                (in_class != *negate) && match_rec(rest, &text[1..])
+            }
+            _ => false,
+        },
+        Token::Star => {
+            for split in 0..=text.len() {
+                if text[..split].contains(&'/') {
+                    break;
+                }
+                if match_rec(rest, &text[split..]) {
+                    return true;
+                }
+            }
+            false
+        }
+        Token::DoubleStar => (0..=text.len()).any(|split| match_rec(rest, &text[split..])),
+        Token::DoubleStarSlash => {
+            // Zero segments: the `**/` contributes nothing.
+            if match_rec(rest, text) {
+                return true;
+            }
+            // One or more segments: consume up to and including each '/' and
+            // try again, still looking for more segments or the zero case.
+            for (i, &c) in text.iter().enumerate() {
+                if c == '/' && match_rec(tokens, &text[i + 1..]) {
+                    return true;
+                }
+            }
+            false
+        }
+    }
+}
+
+/// Returns true if `text` matches the glob `pattern`.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let tokens = tokenize(pattern);
+    let text: Vec<char> = text.chars().collect();
+    match_rec(&tokens, &text)
+}
+
+/// True if any pattern in `patterns` matches this entry.
+///
+/// Single-segment patterns (no `/` and not `**`) are matched against
+/// `basename` at every level; patterns containing `/` or `**` are matched
+/// against `rel`, the path relative to the scan root.
+pub fn matches_any(patterns: &[String], basename: &str, rel: &Path) -> bool {
+    if patterns.is_empty() {
+        return false;
+    }
+    let rel_str = rel.to_string_lossy().replace('\\', "/");
+    patterns.iter().any(|p| {
+        if p.contains('/') || p.contains("**") {
+            glob_match(p, &rel_str)
+        } else {
+            glob_match(p, basename)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn double_star_slash_matches_zero_segments() {
+        assert!(glob_match("src/**/*.rs", "src/lib.rs"));
+        assert!(glob_match("a/**/b", "a/b"));
+        assert!(glob_match("**/foo", "foo"));
+    }
+
+    #[test]
+    fn double_star_slash_matches_one_or_more_segments() {
+        assert!(glob_match("src/**/*.rs", "src/glob/mod.rs"));
+        assert!(glob_match("a/**/b", "a/x/y/b"));
+        assert!(glob_match("**/foo", "a/b/foo"));
+    }
+
+    #[test]
+    fn double_star_slash_still_respects_segment_boundaries() {
+        assert!(!glob_match("a/**/b", "a/bx"));
+        assert!(!glob_match("**/foo", "foobar"));
+    }
+
+    #[test]
+    fn matches_any_includes_files_directly_under_a_double_star_dir() {
+        // The --only flagship example: `src/**/*.rs` must also match files
+        // that sit directly in `src/`, not just ones nested further down.
+        let only = vec!["src/**/*.rs".to_string(), "Cargo.toml".to_string()];
+        assert!(matches_any(&only, "lib.rs", Path::new("src/lib.rs")));
+        assert!(matches_any(&only, "mod.rs", Path::new("src/glob/mod.rs")));
+        assert!(matches_any(&only, "Cargo.toml", Path::new("Cargo.toml")));
+        assert!(!matches_any(&only, "README.md", Path::new("README.md")));
+    }
+}
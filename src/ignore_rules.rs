@@ -0,0 +1,137 @@
+//! `.gitignore`-style ignore file parsing and matching, layered on top of
+//! the glob matcher so `.gitignore`/`.ignore`/`.hgignore` patterns behave
+//! the same way as `--ignore-dir`/`--ignore-file` patterns do.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::glob;
+
+/// One parsed line from a `.gitignore`-style file.
+#[derive(Debug, Clone)]
+pub struct IgnoreRule {
+    /// The pattern with its `!`, trailing `/`, and leading `/` stripped.
+    pattern: String,
+    /// `!pattern` re-includes a path an earlier rule ignored.
+    negate: bool,
+    /// Trailing `/` restricts the rule to directories.
+    dir_only: bool,
+    /// Leading `/` anchors the pattern to `base` instead of matching at any depth.
+    anchored: bool,
+    /// Directory (relative to the scan root) that contains the ignore file.
+    base: PathBuf,
+}
+
+/// The names of ignore files consulted in every directory, in priority order.
+pub const IGNORE_FILE_NAMES: [&str; 3] = [".gitignore", ".ignore", ".hgignore"];
+
+/// Parse a single ignore file. `base` is the directory containing it,
+/// relative to the scan root.
+pub fn parse_file(path: &Path, base: &Path) -> anyhow::Result<Vec<IgnoreRule>> {
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(path)?;
+    Ok(parse_str(&content, base))
+}
+
+fn parse_str(content: &str, base: &Path) -> Vec<IgnoreRule> {
+    let mut rules = Vec::new();
+    for line in content.lines() {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut pattern = line;
+        let negate = pattern.starts_with('!');
+        if negate {
+            pattern = &pattern[1..];
+        }
+
+        let dir_only = pattern.ends_with('/');
+        if dir_only {
+            pattern = &pattern[..pattern.len() - 1];
+        }
+
+        let anchored = pattern.starts_with('/');
+        if anchored {
+            pattern = &pattern[1..];
+        }
+
+        if pattern.is_empty() {
+            continue;
+        }
+
+        rules.push(IgnoreRule {
+            pattern: pattern.to_string(),
+            negate,
+            dir_only,
+            anchored,
+            base: base.to_path_buf(),
+        });
+    }
+    rules
+}
+
+impl IgnoreRule {
+    fn matches(&self, rel: &Path, is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+        let Ok(scoped) = rel.strip_prefix(&self.base) else {
+            return false;
+        };
+        if scoped.as_os_str().is_empty() {
+            return false;
+        }
+        let scoped_str = scoped.to_string_lossy().replace('\\', "/");
+
+        if self.anchored || self.pattern.contains('/') {
+            glob::glob_match(&self.pattern, &scoped_str)
+        } else {
+            // Unanchored, single-segment patterns match at any depth.
+            let basename = scoped
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            glob::glob_match(&self.pattern, &basename)
+                || glob::glob_match(&format!("**/{}", self.pattern), &scoped_str)
+        }
+    }
+}
+
+/// A stack of rule sets, one per ancestor directory from the scan root down
+/// to the directory currently being walked. Parent rules apply to children;
+/// the last matching rule across the whole stack wins, so a child
+/// `.gitignore` (or a later line) can re-include a path a parent ignored.
+pub type RuleStack = Vec<Vec<IgnoreRule>>;
+
+/// Load the ignore rules that live directly in `abs` (the `.gitignore`,
+/// `.ignore`, and `.hgignore` found there, if any), scoped to `rel`.
+pub fn load_dir_rules(abs: &Path, rel: &Path) -> anyhow::Result<Vec<IgnoreRule>> {
+    let mut rules = Vec::new();
+    for name in IGNORE_FILE_NAMES {
+        rules.extend(parse_file(&abs.join(name), rel)?);
+    }
+    Ok(rules)
+}
+
+/// Load `.git/info/exclude` at the scan root, if present.
+pub fn load_git_info_exclude(scan_root: &Path) -> anyhow::Result<Vec<IgnoreRule>> {
+    parse_file(&scan_root.join(".git").join("info").join("exclude"), Path::new(""))
+}
+
+/// True if `rel` (a path relative to the scan root) is ignored by any rule
+/// on the stack, applying the "last match wins" precedence described above.
+pub fn is_ignored(stack: &RuleStack, rel: &Path, is_dir: bool) -> bool {
+    let mut ignored = false;
+    for rules in stack {
+        for rule in rules {
+            if rule.matches(rel, is_dir) {
+                ignored = !rule.negate;
+            }
+        }
+    }
+    ignored
+}
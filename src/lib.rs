@@ -2,6 +2,7 @@ use std::{
     collections::HashSet,
     env,
     fs,
+    io::{Read, Seek, SeekFrom},
     path::{Path, PathBuf},
 };
 
@@ -12,7 +13,9 @@ use once_cell::sync::Lazy;
 use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
 use serde::Deserialize;
-// use yash_fnmatch::{without_escape, Pattern}; 
+
+mod glob;
+mod ignore_rules;
 
 /// ----------  Config that used to live in config.json  ----------
 static DEFAULT_CONFIG: &str = include_str!("config.json");
@@ -29,6 +32,15 @@ struct IgnoreConfig {
 static DEFAULT_IGNORE: Lazy<IgnoreConfig> =
     Lazy::new(|| serde_json::from_str(DEFAULT_CONFIG).expect("embedded config.json is valid"));
 
+/// Which files keep full content first when a token/byte budget is set.
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum BudgetOrder {
+    /// Shallower files first, then smaller ones.
+    Depth,
+    /// Smaller files first, then shallower ones.
+    Size,
+}
+
 /// ----------  Command-line interface  ----------
 #[derive(Parser, Debug)]
 #[command(name = "d2p", about = "Generate a prompt for a directory")]
@@ -37,16 +49,16 @@ struct Cli {
     #[arg(default_value = ".", help = "The directory to generate the prompt for")]
     dir: String,
 
-    /// File-extension filters
-    #[arg(long, num_args = 1.., help = "Filter for and process only files with these extensions (e.g., --filters py rs txt md)")]
+    /// File-extension filters (bare extensions, or glob patterns like `test_*.py`)
+    #[arg(long, num_args = 1.., help = "Filter for and process only files matching these extensions or glob patterns (e.g., --filter py 'test_*.rs')")]
     filter: Vec<String>,
 
-    /// Additional directories to ignore
-    #[arg(long = "ignore-dir", num_args = 1.., help = "Additional directories to ignore (e.g. --ignore-dir experiments __pycache__)")]
+    /// Additional directories to ignore (exact names or glob patterns)
+    #[arg(long = "ignore-dir", num_args = 1.., help = "Additional directories to ignore, by name or glob (e.g. --ignore-dir experiments '__pycache__' 'build-*')")]
     ignore_dirs: Vec<String>,
 
-    /// Additional files to ignore
-    #[arg(long = "ignore-file", num_args = 1.., help = "Additional files or extensions to ignore (e.g. --ignore-file old.py rs)")]
+    /// Additional files to ignore (exact names, extensions, or glob patterns)
+    #[arg(long = "ignore-file", num_args = 1.., help = "Additional files, extensions, or glob patterns to ignore (e.g. --ignore-file old.py rs '*.min.js')")]
     ignore_files: Vec<String>,
 
     /// Output path for prompt file
@@ -72,6 +84,26 @@ struct Cli {
     /// Copy the generated prompt to the clipboard
     #[arg(long = "cp", help = "Copy the generated prompt to the clipboard")]
     cp: bool,
+
+    /// Disable discovery of .gitignore/.ignore/.hgignore files
+    #[arg(long = "no-gitignore", help = "Don't honor .gitignore/.ignore/.hgignore files found while scanning (on by default)")]
+    no_gitignore: bool,
+
+    /// Whitelist of glob patterns; when non-empty, only matching files are emitted
+    #[arg(long = "only", num_args = 1.., help = "Restrict output to files matching these glob patterns (e.g. --only 'src/**/*.rs' Cargo.toml)")]
+    only: Vec<String>,
+
+    /// Cap the generated prompt to roughly this many tokens
+    #[arg(long, help = "Cap the generated prompt to roughly this many tokens (~4 bytes/token), truncating or omitting files once the budget runs out")]
+    max_tokens: Option<usize>,
+
+    /// Cap the generated prompt to this many bytes
+    #[arg(long, help = "Cap the generated prompt to this many bytes, truncating or omitting files once the budget runs out")]
+    max_bytes: Option<usize>,
+
+    /// Which files keep full content first when a budget is set
+    #[arg(long, value_enum, help = "Which files keep full content first when a budget is set: 'depth' (shallower first, default) or 'size' (smaller first)")]
+    budget_order: Option<BudgetOrder>,
 }
 
 /// Exported for use in Python’s console-script stub.
@@ -218,6 +250,11 @@ fn run_cli<I: IntoIterator<Item = String>>(raw_args: I) -> anyhow::Result<()> {
             &dir_ignore,
             &merge(&config.files, &cli.ignore_files),
             cli.tree_only,
+            !cli.no_gitignore,
+            &cli.only,
+            cli.max_tokens,
+            cli.max_bytes,
+            cli.budget_order.unwrap_or(BudgetOrder::Depth),
         )?;
 
         // If tree_only, print the plain text tree to the console.
@@ -236,6 +273,7 @@ fn run_cli<I: IntoIterator<Item = String>>(raw_args: I) -> anyhow::Result<()> {
         let outpath = Path::new(&cli.outpath).join(format!("{outfile}.txt"));
         fs::write(&outpath, &prompt)?;
         println!("Prompt saved to {}", outpath.display().to_string().cyan());
+        println!("Estimated prompt size: {} tokens", estimate_tokens(&prompt));
         Ok(())
     }
 }
@@ -259,26 +297,58 @@ fn load_config(path: &Path) -> anyhow::Result<IgnoreConfig> {
     filter=Vec::<String>::new(),
     ignore_dirs=Vec::<String>::new(),
     ignore_files=Vec::<String>::new(),
-    tree_only=false
+    tree_only=false,
+    respect_gitignore=true,
+    include=Vec::<String>::new(),
+    max_tokens=None,
+    max_bytes=None,
+    budget_order="depth"
 ))]
+#[allow(clippy::too_many_arguments)]
 fn build_prompt(
     dir: &str,
     filter: Vec<String>,
     ignore_dirs: Vec<String>,
     ignore_files: Vec<String>,
     tree_only: bool,
+    respect_gitignore: bool,
+    include: Vec<String>,
+    max_tokens: Option<usize>,
+    max_bytes: Option<usize>,
+    budget_order: &str,
 ) -> PyResult<String> {
-    build_prompt_internal(dir, &filter, &ignore_dirs, &ignore_files, tree_only)
-        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
+    let budget_order = match budget_order {
+        "size" => BudgetOrder::Size,
+        _ => BudgetOrder::Depth,
+    };
+    build_prompt_internal(
+        dir,
+        &filter,
+        &ignore_dirs,
+        &ignore_files,
+        tree_only,
+        respect_gitignore,
+        &include,
+        max_tokens,
+        max_bytes,
+        budget_order,
+    )
+    .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
 }
 
 /// Shared implementation for CLI + Python call
+#[allow(clippy::too_many_arguments)]
 fn build_prompt_internal(
     dir: &str,
     filter: &[String],
     ignore_dirs: &[String],
     ignore_files: &[String],
     tree_only: bool,
+    respect_gitignore: bool,
+    include: &[String],
+    max_tokens: Option<usize>,
+    max_bytes: Option<usize>,
+    budget_order: BudgetOrder,
 ) -> anyhow::Result<String> {
     // 1. Prepare ignore lists
     let dir_path = Path::new(dir);
@@ -317,6 +387,11 @@ fn build_prompt_internal(
     // 2. walk directory, collect files, render tree
     let mut tree = format!("{}/\n", base);
     let mut files = Vec::<PathBuf>::new();
+    let mut ignore_stack: ignore_rules::RuleStack = if respect_gitignore {
+        vec![ignore_rules::load_git_info_exclude(dir_path)?]
+    } else {
+        Vec::new()
+    };
     walk(
         dir_path,
         Path::new(""),
@@ -324,10 +399,26 @@ fn build_prompt_internal(
         ignore_dirs,
         &all_ignore_files,
         &ignore_exts,
+        respect_gitignore,
+        &mut ignore_stack,
         &mut tree,
         &mut files,
     )?;
 
+    // If an include whitelist was given, drop everything else and rebuild the
+    // tree from just the surviving files plus the directories needed to reach
+    // them, rather than threading the whitelist through every walk() frame.
+    if !include.is_empty() {
+        files.retain(|rel| {
+            let basename = rel
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            glob::matches_any(include, &basename, rel)
+        });
+        tree = render_filtered_tree(&base, &files);
+    }
+
     if tree_only {
         return Ok(tree);
     }
@@ -337,30 +428,336 @@ fn build_prompt_internal(
     prompt.push_str(&tree);
     prompt.push_str("</directory_tree>\n\n<files>\n\n");
 
+    // A bare alphanumeric filter like "py" (or ".py", dot and all) historically
+    // meant "any file ending in .py"; keep that shorthand working by
+    // expanding it to a glob so it still routes through the matcher like
+    // explicit patterns do.
+    let normalized_filters: Vec<String> = filter
+        .iter()
+        .map(|f| {
+            let ext = f.strip_prefix('.').unwrap_or(f);
+            if !ext.is_empty() && ext.chars().all(|c| c.is_ascii_alphanumeric()) {
+                format!("*.{}", ext)
+            } else {
+                f.clone()
+            }
+        })
+        .collect();
+
+    let mut candidates = Vec::new();
     for rel in files {
-        let full = dir_path.join(&rel);
-        if filter.is_empty()
-            || filter
-                .iter()
-                .any(|f| rel.to_string_lossy().ends_with(f))
-        {
-            let content =
-                fs::read_to_string(&full).unwrap_or_else(|_| "BINARY OR UNREADABLE".into());
-            prompt.push_str(&format!(
-                "<file>\n<path>{}</path>\n<content>\n{}\n</content>\n</file>\n\n",
-                rel.display(),
-                if content.trim().is_empty() {
-                    "EMPTY FILE"
-                } else {
-                    &content
-                }
-            ));
+        let basename = rel
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if filter.is_empty() || glob::matches_any(&normalized_filters, &basename, &rel) {
+            let content = render_content(&dir_path.join(&rel));
+            candidates.push(Candidate { rel, content });
         }
     }
+
+    // Budget a token/byte cap, if one was given, before rendering; otherwise
+    // every matched file keeps its full content as before. The budget covers
+    // the whole prompt, so seed it with everything that isn't file content:
+    // the header/tree/footer already written plus each file's <file>/<path>/
+    // <content> wrapper, not just the raw content bytes.
+    let budget_bytes = match (max_bytes, max_tokens) {
+        (Some(bytes), Some(tokens)) => Some(bytes.min(tokens * 4)),
+        (Some(bytes), None) => Some(bytes),
+        (None, Some(tokens)) => Some(tokens * 4),
+        (None, None) => None,
+    };
+    let rendered: Vec<(PathBuf, String)> = match budget_bytes {
+        Some(budget) => {
+            let fixed_overhead = prompt.len() + "</files>\n</context>".len();
+            allocate_budget(candidates, budget, budget_order, fixed_overhead)
+                .into_iter()
+                .filter_map(|(rel, content)| content.map(|c| (rel, c)))
+                .collect()
+        }
+        None => candidates
+            .into_iter()
+            .map(|c| (c.rel, c.content))
+            .collect(),
+    };
+
+    for (rel, content) in rendered {
+        prompt.push_str(&format!(
+            "<file>\n<path>{}</path>\n<content>\n{}\n</content>\n</file>\n\n",
+            rel.display(),
+            content
+        ));
+    }
     prompt.push_str("</files>\n</context>");
     Ok(prompt)
 }
 
+/// A file that survived the filter, with its already-rendered `<content>` body.
+struct Candidate {
+    rel: PathBuf,
+    content: String,
+}
+
+/// The fixed `<file>\n<path>...</path>\n<content>\n...\n</content>\n</file>\n\n`
+/// bytes a candidate adds to the prompt besides its own content.
+fn wrapper_overhead(rel: &Path) -> usize {
+    format!(
+        "<file>\n<path>{}</path>\n<content>\n\n</content>\n</file>\n\n",
+        rel.display()
+    )
+    .len()
+}
+
+/// Decide, in priority order, how much of each candidate's content survives
+/// a token/byte budget: full, or line-truncated with a marker. `used` starts
+/// at the size of everything that isn't file content (the header, directory
+/// tree, and footer already written), and every byte a candidate adds —
+/// wrapper and all — is charged back against it, so the running total always
+/// matches what's actually written to the prompt. Once `used` reaches
+/// `budget`, remaining candidates are dropped entirely (`None`): they still
+/// show up in the directory tree, just not as `<file>` entries, which is what
+/// keeps the output bounded instead of growing with the file count. Returns
+/// candidates in their original (tree) order so the output layout doesn't
+/// change just because a budget was set.
+fn allocate_budget(
+    candidates: Vec<Candidate>,
+    budget: usize,
+    order: BudgetOrder,
+    mut used: usize,
+) -> Vec<(PathBuf, Option<String>)> {
+    let mut priority: Vec<usize> = (0..candidates.len()).collect();
+    priority.sort_by_key(|&i| {
+        let depth = candidates[i].rel.components().count();
+        let size = candidates[i].content.len();
+        match order {
+            BudgetOrder::Depth => (depth, size),
+            BudgetOrder::Size => (size, depth),
+        }
+    });
+
+    let mut decided: Vec<Option<String>> = vec![None; candidates.len()];
+    for i in priority {
+        if used >= budget {
+            // Nothing left to spend, not even on a placeholder: leave this
+            // file out of <files> entirely rather than growing the prompt
+            // by one more block per omitted file.
+            continue;
+        }
+        let overhead = wrapper_overhead(&candidates[i].rel);
+        let remaining = budget - used;
+        if overhead >= remaining {
+            continue;
+        }
+        let available = remaining - overhead;
+        let content = &candidates[i].content;
+        let body = if content.len() <= available {
+            content.clone()
+        } else {
+            truncate_content(content, available)
+        };
+        used += overhead + body.len();
+        decided[i] = Some(body);
+    }
+
+    candidates
+        .into_iter()
+        .zip(decided)
+        .map(|(c, content)| (c.rel, content))
+        .collect()
+}
+
+/// Keep as many whole lines of `content` as fit in `remaining` bytes,
+/// appending a marker noting how many lines were dropped. Reserves room for
+/// the marker itself before filling lines, so the returned string's length
+/// never exceeds `remaining`; when even the marker doesn't fit, it is left
+/// off and the result is empty.
+fn truncate_content(content: &str, remaining: usize) -> String {
+    let lines: Vec<&str> = content.lines().collect();
+    let total = lines.len();
+
+    // The marker's length grows with the omitted count, which is at most
+    // `total`; reserve for that worst case up front.
+    let marker_len = |omitted: usize| {
+        format!("<!-- truncated: {} of {} lines omitted -->", omitted, total).len()
+    };
+    let lines_budget = remaining.saturating_sub(marker_len(total));
+
+    let mut out = String::new();
+    let mut kept = 0;
+    for line in &lines {
+        if out.len() + line.len() + 1 > lines_budget {
+            break;
+        }
+        out.push_str(line);
+        out.push('\n');
+        kept += 1;
+    }
+    let omitted = total.saturating_sub(kept);
+    if omitted > 0 {
+        let marker = format!("<!-- truncated: {} of {} lines omitted -->", omitted, total);
+        if out.len() + marker.len() <= remaining {
+            out.push_str(&marker);
+        }
+    }
+    out
+}
+
+/// How many leading bytes of a regular file we sniff to decide if it's binary.
+const BINARY_SNIFF_LEN: usize = 8 * 1024;
+
+/// Classify `full` and render its `<content>` body. Special files are never
+/// opened, and a file with a NUL byte in its first `BINARY_SNIFF_LEN` bytes is
+/// not read past them; any other file is read whole, and reported as binary
+/// if it is not valid UTF-8.
+fn render_content(full: &Path) -> String {
+    let meta = match fs::symlink_metadata(full) {
+        Ok(m) => m,
+        Err(e) => return format!("UNREADABLE ({})", e),
+    };
+    let file_type = meta.file_type();
+
+    if file_type.is_symlink() {
+        return match fs::read_link(full) {
+            Ok(target) => format!("SYMLINK -> {}", target.display()),
+            Err(e) => format!("SYMLINK (unreadable target: {})", e),
+        };
+    }
+    if let Some(kind) = special_kind(&file_type) {
+        return format!("{} (skipped)", kind);
+    }
+    if file_type.is_dir() {
+        return "DIRECTORY (skipped)".to_string();
+    }
+
+    let mut file = match fs::File::open(full) {
+        Ok(f) => f,
+        Err(e) => return format!("UNREADABLE ({})", e),
+    };
+
+    let mut probe = [0u8; BINARY_SNIFF_LEN];
+    let probed = match file.read(&mut probe) {
+        Ok(n) => n,
+        Err(e) => return format!("UNREADABLE ({})", e),
+    };
+    if probe[..probed].contains(&0) {
+        let size = meta.len();
+        return format!("BINARY FILE ({}, skipped)", human_size(size));
+    }
+
+    let mut content = String::new();
+    if file.seek(SeekFrom::Start(0)).is_err() || file.read_to_string(&mut content).is_err() {
+        return format!("BINARY FILE ({}, skipped)", human_size(meta.len()));
+    }
+
+    if content.trim().is_empty() {
+        "EMPTY FILE".to_string()
+    } else {
+        content
+    }
+}
+
+/// Name special (non-regular, non-symlink) file types, unix-only since that's
+/// where FIFOs/sockets/device files actually show up.
+#[cfg(unix)]
+fn special_kind(file_type: &fs::FileType) -> Option<&'static str> {
+    use std::os::unix::fs::FileTypeExt;
+    if file_type.is_fifo() {
+        Some("FIFO")
+    } else if file_type.is_socket() {
+        Some("SOCKET")
+    } else if file_type.is_block_device() {
+        Some("BLOCK DEVICE")
+    } else if file_type.is_char_device() {
+        Some("CHARACTER DEVICE")
+    } else {
+        None
+    }
+}
+
+#[cfg(not(unix))]
+fn special_kind(_file_type: &fs::FileType) -> Option<&'static str> {
+    None
+}
+
+/// Render a byte count as a human-readable size, e.g. "12.3 KB".
+fn human_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// Rebuild a directory-tree string containing only `files` and the
+/// intermediate directories needed to reach them, in the same connector
+/// style as `walk()`. Used by the `--only` whitelist, where pruning happens
+/// after the walk instead of threading the include list through every frame.
+fn render_filtered_tree(base: &str, files: &[PathBuf]) -> String {
+    use std::collections::BTreeMap;
+
+    enum Node {
+        File,
+        Dir(BTreeMap<String, Node>),
+    }
+
+    fn insert(root: &mut BTreeMap<String, Node>, rel: &Path) {
+        let components: Vec<String> = rel
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        let mut node = root;
+        for (i, comp) in components.iter().enumerate() {
+            if i + 1 == components.len() {
+                node.insert(comp.clone(), Node::File);
+            } else {
+                node = match node
+                    .entry(comp.clone())
+                    .or_insert_with(|| Node::Dir(BTreeMap::new()))
+                {
+                    Node::Dir(children) => children,
+                    Node::File => return, // a file can't also be a directory; ignore the clash
+                };
+            }
+        }
+    }
+
+    fn render(level: &BTreeMap<String, Node>, indent: &str, out: &mut String) {
+        let entries: Vec<_> = level.iter().collect();
+        for (i, (name, node)) in entries.iter().enumerate() {
+            let is_last = i + 1 == entries.len();
+            let connector = if is_last { "└── " } else { "├── " };
+            out.push_str(indent);
+            out.push_str(connector);
+            out.push_str(name);
+            match node {
+                Node::Dir(children) => {
+                    out.push_str("/\n");
+                    let child_indent =
+                        format!("{}{}", indent, if is_last { "    " } else { "│   " });
+                    render(children, &child_indent, out);
+                }
+                Node::File => out.push('\n'),
+            }
+        }
+    }
+
+    let mut root = BTreeMap::new();
+    for rel in files {
+        insert(&mut root, rel);
+    }
+
+    let mut tree = format!("{}/\n", base);
+    render(&root, "", &mut tree);
+    tree
+}
+
 /// Collect non-ignored directory names
 fn collect_dirs(abs: &Path, dir_ignores: &[String]) -> anyhow::Result<HashSet<String>> {
     let mut dirs = HashSet::new();
@@ -398,9 +795,15 @@ fn walk(
     ignore_dirs: &[String],
     ignore_files: &[String],
     ignore_exts: &HashSet<String>,
+    respect_gitignore: bool,
+    ignore_stack: &mut ignore_rules::RuleStack,
     tree: &mut String,
     files: &mut Vec<PathBuf>,
 ) -> anyhow::Result<()> {
+    if respect_gitignore {
+        ignore_stack.push(ignore_rules::load_dir_rules(abs, rel)?);
+    }
+
     let mut visible_entries: Vec<String> = Vec::new();
     for entry_res in fs::read_dir(abs)? {
         if let Ok(dir_entry) = entry_res {
@@ -410,28 +813,29 @@ fn walk(
             // --- IGNORE LOGIC ---
 
             // 1. Check for dotfiles, with exceptions for .env.example files.
-            if entry_name.starts_with('.') 
-                && entry_name != ".env.example" 
+            if entry_name.starts_with('.')
+                && entry_name != ".env.example"
                 && entry_name != ".example.env" {
                 continue;
             }
 
             let abs_path = abs.join(entry_name.as_ref());
             let is_dir = abs_path.is_dir();
+            let entry_rel = rel.join(entry_name.as_ref());
 
-            // 2. Check against ignore lists using exact matches.
+            // 2. Check against ignore lists, via glob patterns.
             let ignore = if is_dir {
-                // Exact match for directory names
-                ignore_dirs.contains(&entry_name.to_string())
+                glob::matches_any(ignore_dirs, &entry_name, &entry_rel)
             } else {
-                // Exact match for full filename OR file extension
-                ignore_files.contains(&entry_name.to_string()) ||
-                abs_path.extension()
-                    .and_then(|s| s.to_str())
-                    .map(|ext| ignore_exts.contains(&ext.to_lowercase()))
-                    .unwrap_or(false)
-            };
-
+                glob::matches_any(ignore_files, &entry_name, &entry_rel)
+                    || abs_path
+                        .extension()
+                        .and_then(|s| s.to_str())
+                        .map(|ext| ignore_exts.contains(&ext.to_lowercase()))
+                        .unwrap_or(false)
+            }
+            // 3. Check against discovered .gitignore/.ignore/.hgignore rules.
+            || (respect_gitignore && ignore_rules::is_ignored(ignore_stack, &entry_rel, is_dir));
 
             if !ignore {
                 visible_entries.push(entry_name.into_owned());
@@ -458,6 +862,8 @@ fn walk(
                 ignore_dirs,
                 ignore_files,
                 ignore_exts,
+                respect_gitignore,
+                ignore_stack,
                 tree,
                 files,
             )?;
@@ -466,13 +872,30 @@ fn walk(
             files.push(rel.join(entry));
         }
     }
+
+    if respect_gitignore {
+        ignore_stack.pop();
+    }
     Ok(())
 }
 
+/// Cheap ~4-bytes-per-token estimate for `--max-tokens` and for callers who
+/// want to gauge a generated prompt's size without reimplementing the heuristic.
+fn estimate_tokens(text: &str) -> usize {
+    (text.len() + 3) / 4
+}
+
+/// ----------  Python-facing token estimate  ----------
+#[pyfunction]
+fn estimate_token_count(text: &str) -> usize {
+    estimate_tokens(text)
+}
+
 /// ----------  Python module entry-point  ----------
 #[pymodule]
 fn dir2prompt(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(build_prompt, m)?)?;
     m.add_function(wrap_pyfunction!(cli, m)?)?;
+    m.add_function(wrap_pyfunction!(estimate_token_count, m)?)?;
     Ok(())
 }
\ No newline at end of file